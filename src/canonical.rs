@@ -0,0 +1,283 @@
+//! Parsing Canonical Form (PCF) and the CRC-64-AVRO Rabin fingerprint, as
+//! defined by the [Avro specification](https://avro.apache.org/docs/current/specification/#schema-fingerprints).
+
+use std::collections::HashSet;
+
+use super::*;
+
+/// `EMPTY` from the spec: the CRC-64-AVRO polynomial used to seed and fold
+/// the Rabin fingerprint.
+const FINGERPRINT_EMPTY: u64 = 0xc15d_213a_a4d7_a795;
+
+fn fingerprint_table() -> [u64; 256] {
+    let mut table = [0u64; 256];
+    for (i, entry) in table.iter_mut().enumerate() {
+        let mut fp = i as u64;
+        for _ in 0..8 {
+            fp = (fp >> 1) ^ (FINGERPRINT_EMPTY & (0u64.wrapping_sub(fp & 1)));
+        }
+        *entry = fp;
+    }
+    table
+}
+
+impl Schema {
+    /// Renders the schema's [Parsing Canonical Form](https://avro.apache.org/docs/current/specification/#parsing-canonical-form-for-schemas):
+    /// `doc`, `aliases`, `default` and `order` are stripped, names are
+    /// replaced by their fullname, named-type attributes are emitted in a
+    /// fixed order, and there is no insignificant whitespace.
+    pub fn canonical_form(&self) -> String {
+        let mut out = String::new();
+        let mut emitted = HashSet::new();
+        write_canonical(self, None, &mut emitted, &mut out);
+        out
+    }
+
+    /// The 64-bit CRC-64-AVRO Rabin fingerprint of this schema's Parsing
+    /// Canonical Form, as used for `fingerprint.avsc`-style schema identity.
+    pub fn fingerprint_rabin(&self) -> u64 {
+        rabin_fingerprint(self.canonical_form().as_bytes())
+    }
+}
+
+fn rabin_fingerprint(bytes: &[u8]) -> u64 {
+    let table = fingerprint_table();
+    let mut fp = FINGERPRINT_EMPTY;
+    for &b in bytes {
+        fp = (fp >> 8) ^ table[((fp ^ b as u64) & 0xff) as usize];
+    }
+    fp
+}
+
+fn json_string_escape(s: &str, out: &mut String) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Writes a named type's PCF. The spec's canonical form defines a named
+/// type's definition exactly once; every later reference to the same
+/// fullname (anywhere in the schema, not just this subtree) is just the
+/// fullname string, as `emitted` tracks.
+fn write_named_type(
+    fullname: &str,
+    emitted: &mut HashSet<String>,
+    out: &mut String,
+    write_definition: impl FnOnce(&mut HashSet<String>, &mut String),
+) {
+    if !emitted.insert(fullname.to_string()) {
+        json_string_escape(fullname, out);
+        return;
+    }
+    out.push_str("{\"name\":");
+    json_string_escape(fullname, out);
+    write_definition(emitted, out);
+    out.push('}');
+}
+
+fn write_canonical(
+    schema: &Schema,
+    enclosing_namespace: Option<&str>,
+    emitted: &mut HashSet<String>,
+    out: &mut String,
+) {
+    use Schema::*;
+    match schema {
+        Null => out.push_str("\"null\""),
+        Boolean => out.push_str("\"boolean\""),
+        Int => out.push_str("\"int\""),
+        Long => out.push_str("\"long\""),
+        Float => out.push_str("\"float\""),
+        Double => out.push_str("\"double\""),
+        Bytes => out.push_str("\"bytes\""),
+        String => out.push_str("\"string\""),
+        Uuid => out.push_str("\"string\""),
+        Date | TimeMillis => out.push_str("\"int\""),
+        TimeMicros | TimestampMillis | TimestampMicros => out.push_str("\"long\""),
+        Decimal { inner, .. } => write_canonical(inner, enclosing_namespace, emitted, out),
+        Reference(name) => json_string_escape(&super::de::fullname(name, enclosing_namespace), out),
+        Union(variants) => {
+            out.push('[');
+            for (i, variant) in variants.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(variant, enclosing_namespace, emitted, out);
+            }
+            out.push(']');
+        }
+        Array(items) => {
+            out.push_str("{\"type\":\"array\",\"items\":");
+            write_canonical(items, enclosing_namespace, emitted, out);
+            out.push('}');
+        }
+        Map(values) => {
+            out.push_str("{\"type\":\"map\",\"values\":");
+            write_canonical(values, enclosing_namespace, emitted, out);
+            out.push('}');
+        }
+        Record(record) => {
+            let fullname = super::de::fullname(&record.name, record.namespace.as_deref());
+            let namespace = fullname.rsplit_once('.').map(|(ns, _)| ns.to_string());
+            write_named_type(&fullname, emitted, out, |emitted, out| {
+                out.push_str(",\"type\":\"record\",\"fields\":[");
+                for (i, field) in record.fields.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    out.push_str("{\"name\":");
+                    json_string_escape(&field.name, out);
+                    out.push_str(",\"type\":");
+                    write_canonical(&field.schema, namespace.as_deref(), emitted, out);
+                    out.push('}');
+                }
+                out.push_str("]}");
+                // the closing `}` for the record itself is appended by `write_named_type`
+                out.pop();
+            });
+        }
+        Enum(enum_) => {
+            let fullname = super::de::fullname(&enum_.name, enum_.namespace.as_deref());
+            write_named_type(&fullname, emitted, out, |_emitted, out| {
+                out.push_str(",\"type\":\"enum\",\"symbols\":[");
+                for (i, symbol) in enum_.symbols.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    json_string_escape(symbol, out);
+                }
+                out.push(']');
+            });
+        }
+        Fixed(fixed) | Duration(fixed) => {
+            let fullname = super::de::fullname(&fixed.name, fixed.namespace.as_deref());
+            write_named_type(&fullname, emitted, out, |_emitted, out| {
+                out.push_str(",\"type\":\"fixed\",\"size\":");
+                out.push_str(&fixed.size.to_string());
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_form_of_primitives() {
+        assert_eq!(Schema::Int.canonical_form(), "\"int\"");
+        assert_eq!(Schema::String.canonical_form(), "\"string\"");
+        assert_eq!(Schema::parse_str("\"null\"").unwrap().canonical_form(), "\"null\"");
+    }
+
+    #[test]
+    fn canonical_form_strips_doc_default_and_order() {
+        let schema = Schema::parse_str(
+            r#"{
+                "type": "record",
+                "name": "Foo",
+                "doc": "a record",
+                "fields": [
+                    {"name": "a", "type": "int", "default": 0, "order": "descending", "doc": "a field"}
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            schema.canonical_form(),
+            r#"{"name":"Foo","type":"record","fields":[{"name":"a","type":"int"}]}"#
+        );
+    }
+
+    /// A named type referenced more than once must be defined exactly once in
+    /// the canonical form, with later references replaced by its fullname.
+    #[test]
+    fn canonical_form_emits_named_types_once() {
+        let schema = Schema::parse_str(
+            r#"{
+                "type": "record",
+                "name": "Pair",
+                "fields": [
+                    {"name": "a", "type": {"type": "enum", "name": "Color", "symbols": ["RED", "GREEN", "BLUE"]}},
+                    {"name": "b", "type": "Color"}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let cf = schema.canonical_form();
+        assert_eq!(cf.matches("\"symbols\"").count(), 1);
+        assert_eq!(
+            cf,
+            r#"{"name":"Pair","type":"record","fields":[{"name":"a","type":{"name":"Color","type":"enum","symbols":["RED","GREEN","BLUE"]}},{"name":"b","type":"Color"}]}"#
+        );
+    }
+
+    /// A nested named type that inherits its namespace from an enclosing
+    /// record must still be rendered with its full (inherited) name in PCF,
+    /// per spec, including when referenced again later by a bare name.
+    #[test]
+    fn canonical_form_uses_inherited_namespace_for_nested_named_types() {
+        let schema = Schema::parse_str(
+            r#"{
+                "type": "record",
+                "name": "Outer",
+                "namespace": "com.example",
+                "fields": [
+                    {"name": "inner", "type": {"type": "record", "name": "Inner", "fields": [{"name": "x", "type": "int"}]}},
+                    {"name": "ref2", "type": "Inner"}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let cf = schema.canonical_form();
+        assert_eq!(
+            cf,
+            r#"{"name":"com.example.Outer","type":"record","fields":[{"name":"inner","type":{"name":"com.example.Inner","type":"record","fields":[{"name":"x","type":"int"}]}},{"name":"ref2","type":"com.example.Inner"}]}"#
+        );
+    }
+
+    /// An independent, non-table bit-by-bit CRC-64-AVRO implementation (per
+    /// the same algorithm in the spec, but without the lookup table), used to
+    /// cross-check `rabin_fingerprint`'s table-based output. We have no
+    /// network access in this environment to pull an Apache-published
+    /// fingerprint vector, so this re-derives the value independently instead
+    /// of pinning a number copied from memory.
+    fn fingerprint_bitwise(bytes: &[u8]) -> u64 {
+        let mut fp = FINGERPRINT_EMPTY;
+        for &b in bytes {
+            fp ^= b as u64;
+            for _ in 0..8 {
+                fp = if fp & 1 == 1 { (fp >> 1) ^ FINGERPRINT_EMPTY } else { fp >> 1 };
+            }
+        }
+        fp
+    }
+
+    #[test]
+    fn fingerprint_rabin_matches_independent_bitwise_implementation() {
+        for json in [
+            "\"null\"",
+            "\"int\"",
+            "\"string\"",
+            r#"{"type":"array","items":"long"}"#,
+        ] {
+            let schema = Schema::parse_str(json).unwrap();
+            assert_eq!(
+                schema.fingerprint_rabin(),
+                fingerprint_bitwise(schema.canonical_form().as_bytes()),
+                "mismatch for {}",
+                json
+            );
+        }
+    }
+}
@@ -1,4 +1,8 @@
-use std::{collections::HashMap, fmt};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    fmt,
+};
 
 use serde::{
     de::{MapAccess, SeqAccess, Visitor},
@@ -8,6 +12,59 @@ use serde_json::Value;
 
 use super::*;
 
+thread_local! {
+    // Fullname -> previously-defined named schema, populated as records/enums/fixed
+    // are parsed so that later `"type": "some.Name"` references can be resolved.
+    static NAMED_SCHEMAS: RefCell<HashMap<String, Schema>> = RefCell::new(HashMap::new());
+    // The enclosing namespace at each nesting level, used to resolve names that
+    // don't carry their own `namespace` and to compute fullnames for registration.
+    static NAMESPACE_SCOPE: RefCell<Vec<Option<String>>> = RefCell::new(vec![None]);
+    // Counts re-entrant calls into `Schema`'s `Deserialize` impl, so the
+    // resolution state above is reset once per independent top-level parse
+    // and left untouched for the nested calls a record's fields, a union's
+    // branches, etc. make while that parse is still in progress.
+    static PARSE_DEPTH: Cell<u32> = const { Cell::new(0) };
+}
+
+/// Clears the named-type resolution table. Call this before parsing a new,
+/// independent top-level schema so references don't leak between schemas.
+pub(crate) fn reset_resolution_state() {
+    NAMED_SCHEMAS.with(|t| t.borrow_mut().clear());
+    NAMESPACE_SCOPE.with(|s| *s.borrow_mut() = vec![None]);
+}
+
+fn current_namespace() -> Option<String> {
+    NAMESPACE_SCOPE.with(|s| s.borrow().last().cloned().flatten())
+}
+
+pub(crate) fn fullname(name: &str, namespace: Option<&str>) -> String {
+    if name.contains('.') {
+        name.to_string()
+    } else if let Some(namespace) = namespace {
+        format!("{}.{}", namespace, name)
+    } else {
+        name.to_string()
+    }
+}
+
+fn register_named_schema(name: &str, namespace: Option<&str>, aliases: &[String], schema: &Schema) {
+    NAMED_SCHEMAS.with(|t| {
+        let mut t = t.borrow_mut();
+        t.insert(fullname(name, namespace), schema.clone());
+        for alias in aliases {
+            t.insert(fullname(alias, namespace), schema.clone());
+        }
+    });
+}
+
+fn resolve_reference(type_: &str) -> Option<Schema> {
+    let scoped = fullname(type_, current_namespace().as_deref());
+    NAMED_SCHEMAS.with(|t| {
+        let t = t.borrow();
+        t.get(&scoped).or_else(|| t.get(type_)).cloned()
+    })
+}
+
 fn to_primitive(v: &str) -> Option<Schema> {
     use Schema::*;
     Some(match v {
@@ -75,16 +132,72 @@ fn remove_vec_string<E: serde::de::Error>(
     }
 }
 
+fn remove_usize<E: serde::de::Error>(
+    data: &mut HashMap<String, Value>,
+    key: &str,
+) -> Result<Option<usize>, E> {
+    match data.remove(key) {
+        Some(v) => serde_json::from_value(v).map(Some).map_err(E::custom),
+        None => Ok(None),
+    }
+}
+
+/// Applies a `logicalType` annotation on top of its underlying base schema,
+/// validating the constraints from the Avro spec. Unrecognized `logicalType`s
+/// and schemas that fail validation silently fall back to the base type, per
+/// the spec's "ignore unrecognized logicalType" rule.
+fn to_logical<E: serde::de::Error>(
+    base: Schema,
+    logical_type: &str,
+    map: &mut HashMap<String, Value>,
+) -> Result<Schema, E> {
+    let precision = remove_usize(map, "precision")?;
+    let scale = remove_usize(map, "scale")?.unwrap_or(0);
+
+    let logical = match (logical_type, &base) {
+        ("decimal", Schema::Bytes) | ("decimal", Schema::Fixed(_)) => match precision {
+            Some(precision) if precision > 0 && scale <= precision => Some(Schema::Decimal {
+                precision,
+                scale,
+                inner: Box::new(base.clone()),
+            }),
+            _ => None,
+        },
+        ("uuid", Schema::String) => Some(Schema::Uuid),
+        ("date", Schema::Int) => Some(Schema::Date),
+        ("time-millis", Schema::Int) => Some(Schema::TimeMillis),
+        ("time-micros", Schema::Long) => Some(Schema::TimeMicros),
+        ("timestamp-millis", Schema::Long) => Some(Schema::TimestampMillis),
+        ("timestamp-micros", Schema::Long) => Some(Schema::TimestampMicros),
+        ("duration", Schema::Fixed(fixed)) if fixed.size == 12 => {
+            Some(Schema::Duration(fixed.clone()))
+        }
+        _ => None,
+    };
+    Ok(logical.unwrap_or(base))
+}
+
 fn to_enum<E: serde::de::Error>(data: &mut HashMap<String, Value>) -> Result<Schema, E> {
-    Ok(Schema::Enum(Enum {
-        name: remove_string(data, "name")?
-            .ok_or_else(|| serde::de::Error::custom("name is required in enum"))?,
-        namespace: remove_string(data, "namespace")?,
-        aliases: remove_vec_string(data, "aliases")?,
+    let name = remove_string(data, "name")?
+        .ok_or_else(|| serde::de::Error::custom("name is required in enum"))?;
+    let namespace = remove_string(data, "namespace")?;
+    // Store the effective (possibly inherited) namespace rather than the raw
+    // one, so downstream consumers (PCF, resolution) see the fullname this
+    // type actually resolves to without needing their own enclosing-scope
+    // bookkeeping.
+    let effective_namespace = namespace.or_else(current_namespace);
+    let aliases = remove_vec_string(data, "aliases")?;
+
+    let schema = Schema::Enum(Enum {
+        name: name.clone(),
+        namespace: effective_namespace.clone(),
+        aliases: aliases.clone(),
         doc: remove_string(data, "doc")?,
         symbols: remove_vec_string(data, "symbols")?,
         default: remove_string(data, "default")?,
-    }))
+    });
+    register_named_schema(&name, effective_namespace.as_deref(), &aliases, &schema);
+    Ok(schema)
 }
 
 fn to_map<E: serde::de::Error>(data: &mut HashMap<String, Value>) -> Result<Schema, E> {
@@ -132,14 +245,34 @@ fn to_vec_fields<E: serde::de::Error>(
 }
 
 fn to_record<E: serde::de::Error>(data: &mut HashMap<String, Value>) -> Result<Schema, E> {
-    Ok(Schema::Record(Record {
-        name: remove_string(data, "name")?
-            .ok_or_else(|| serde::de::Error::custom("name is required in enum"))?,
-        namespace: remove_string(data, "namespace")?,
-        aliases: remove_vec_string(data, "aliases")?,
-        doc: remove_string(data, "doc")?,
-        fields: to_vec_fields(data, "fields")?,
-    }))
+    let name = remove_string(data, "name")?
+        .ok_or_else(|| serde::de::Error::custom("name is required in enum"))?;
+    let namespace = remove_string(data, "namespace")?;
+    let effective_namespace = namespace.or_else(current_namespace);
+    let aliases = remove_vec_string(data, "aliases")?;
+    let doc = remove_string(data, "doc")?;
+
+    // Fields are resolved under this record's namespace, so nested named types
+    // that omit their own `namespace` inherit it, per the Avro spec.
+    NAMESPACE_SCOPE.with(|s| s.borrow_mut().push(effective_namespace.clone()));
+    let fields = to_vec_fields(data, "fields");
+    NAMESPACE_SCOPE.with(|s| {
+        s.borrow_mut().pop();
+    });
+
+    // Store the effective (possibly inherited) namespace rather than the raw
+    // one, so downstream consumers (PCF, resolution) see the fullname this
+    // type actually resolves to without needing their own enclosing-scope
+    // bookkeeping.
+    let schema = Schema::Record(Record {
+        name: name.clone(),
+        namespace: effective_namespace.clone(),
+        aliases: aliases.clone(),
+        doc,
+        fields: fields?,
+    });
+    register_named_schema(&name, effective_namespace.as_deref(), &aliases, &schema);
+    Ok(schema)
 }
 
 fn to_fixed<E: serde::de::Error>(data: &mut HashMap<String, Value>) -> Result<Schema, E> {
@@ -147,14 +280,25 @@ fn to_fixed<E: serde::de::Error>(data: &mut HashMap<String, Value>) -> Result<Sc
         .remove("size")
         .ok_or_else(|| serde::de::Error::custom("name is required in enum"))
         .and_then(|x| serde_json::from_value::<usize>(x).map_err(serde::de::Error::custom))?;
-    Ok(Schema::Fixed(Fixed {
-        name: remove_string(data, "name")?
-            .ok_or_else(|| serde::de::Error::custom("name is required in enum"))?,
-        namespace: remove_string(data, "namespace")?,
-        aliases: remove_vec_string(data, "aliases")?,
+    let name = remove_string(data, "name")?
+        .ok_or_else(|| serde::de::Error::custom("name is required in enum"))?;
+    let namespace = remove_string(data, "namespace")?;
+    // Store the effective (possibly inherited) namespace rather than the raw
+    // one, so downstream consumers (PCF, resolution) see the fullname this
+    // type actually resolves to without needing their own enclosing-scope
+    // bookkeeping.
+    let effective_namespace = namespace.or_else(current_namespace);
+    let aliases = remove_vec_string(data, "aliases")?;
+
+    let schema = Schema::Fixed(Fixed {
+        name: name.clone(),
+        namespace: effective_namespace.clone(),
+        aliases: aliases.clone(),
         doc: remove_string(data, "doc")?,
         size,
-    }))
+    });
+    register_named_schema(&name, effective_namespace.as_deref(), &aliases, &schema);
+    Ok(schema)
 }
 
 fn to_order<E: serde::de::Error>(
@@ -191,8 +335,12 @@ impl<'de> Visitor<'de> for SchemaVisitor {
     where
         E: serde::de::Error,
     {
-        to_primitive(v)
-            .ok_or_else(|| serde::de::Error::custom("string must be a valid primitive Schema"))
+        // A bare string is either a primitive keyword or a reference to a
+        // named type defined earlier in the schema (e.g. a field whose
+        // `"type"` is `"com.example.Address"`, or a union branch name).
+        Ok(to_primitive(v)
+            .or_else(|| resolve_reference(v))
+            .unwrap_or_else(|| Schema::Reference(v.to_string())))
     }
 
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
@@ -220,17 +368,28 @@ impl<'de> Visitor<'de> for SchemaVisitor {
         }
 
         let (schema, type_) = get_type(&mut map).map(|x| (to_primitive(&x), x))?;
+        let logical_type = remove_string(&mut map, "logicalType")?;
 
         if let Some(schema) = schema {
-            Ok(schema)
+            match logical_type {
+                Some(logical_type) => to_logical(schema, &logical_type, &mut map),
+                None => Ok(schema),
+            }
         } else {
             match type_.as_ref() {
                 "enum" => to_enum(&mut map),
                 "map" => to_map(&mut map),
                 "array" => to_array(&mut map),
                 "record" => to_record(&mut map),
-                "fixed" => to_fixed(&mut map),
-                other => todo!("{}", other),
+                "fixed" => {
+                    let fixed = to_fixed(&mut map)?;
+                    match logical_type {
+                        Some(logical_type) => to_logical(fixed, &logical_type, &mut map),
+                        None => Ok(fixed),
+                    }
+                }
+                other => Ok(resolve_reference(other)
+                    .unwrap_or_else(|| Schema::Reference(other.to_string()))),
             }
         }
     }
@@ -241,7 +400,21 @@ impl<'de> Deserialize<'de> for Schema {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_any(SchemaVisitor {})
+        // Reset the named-type resolution state on the outermost call only,
+        // so it survives the nested calls this impl makes on itself while
+        // parsing a record's fields, a union's branches, etc., but never
+        // leaks into an unrelated schema parsed afterwards on this thread.
+        let is_top_level = PARSE_DEPTH.with(|depth| {
+            let was = depth.get();
+            depth.set(was + 1);
+            was == 0
+        });
+        if is_top_level {
+            reset_resolution_state();
+        }
+        let result = deserializer.deserialize_any(SchemaVisitor {});
+        PARSE_DEPTH.with(|depth| depth.set(depth.get() - 1));
+        result
     }
 }
 
@@ -272,7 +445,7 @@ impl<'de> Visitor<'de> for FieldVisitor {
             doc: remove_string(&mut map, "doc")?,
             schema: to_schema(&mut map, "type")?
                 .ok_or_else(|| serde::de::Error::custom("schema is required in Field"))?,
-            default: to_schema(&mut map, "default")?,
+            default: map.remove("default"),
             order: to_order(&mut map, "order")?,
             aliases: remove_vec_string(&mut map, "aliases")?,
         })
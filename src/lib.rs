@@ -1,4 +1,11 @@
+mod canonical;
 mod de;
+mod resolve;
+mod ser;
+mod validate;
+
+pub use resolve::Incompatibility;
+pub use validate::ParseOptions;
 
 #[derive(Debug, Clone, Copy, PartialEq, Hash)]
 pub enum Order {
@@ -7,16 +14,30 @@ pub enum Order {
     Ignore,
 }
 
-#[derive(Debug, Clone, PartialEq, Hash)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Field {
     pub name: String,
     pub doc: Option<String>,
     pub schema: Schema,
-    pub default: Option<Schema>,
+    pub default: Option<serde_json::Value>,
     pub order: Option<Order>,
     pub aliases: Vec<String>,
 }
 
+impl std::hash::Hash for Field {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.doc.hash(state);
+        self.schema.hash(state);
+        // `serde_json::Value` can hold an `f64` and so doesn't implement
+        // `Hash` itself; its canonical JSON string is a stable stand-in that
+        // still agrees with `PartialEq`.
+        self.default.as_ref().map(serde_json::Value::to_string).hash(state);
+        self.order.hash(state);
+        self.aliases.hash(state);
+    }
+}
+
 impl Field {
     pub fn new<I: Into<String>>(name: I, schema: Schema) -> Self {
         Self {
@@ -111,6 +132,31 @@ pub enum Schema {
     Map(Box<Schema>),
     Union(Vec<Schema>),
     Fixed(Fixed),
+    /// An unresolved reference to a named type, kept as the name written in
+    /// the `"type"` string (not qualified by the enclosing namespace) when it
+    /// doesn't match a type previously defined in the schema being parsed
+    /// (e.g. a forward reference).
+    Reference(String),
+    /// `{"type": "bytes"|"fixed", "logicalType": "decimal", "precision": _, "scale": _}`
+    Decimal {
+        precision: usize,
+        scale: usize,
+        inner: Box<Schema>,
+    },
+    /// `{"type": "string", "logicalType": "uuid"}`
+    Uuid,
+    /// `{"type": "int", "logicalType": "date"}`
+    Date,
+    /// `{"type": "int", "logicalType": "time-millis"}`
+    TimeMillis,
+    /// `{"type": "long", "logicalType": "time-micros"}`
+    TimeMicros,
+    /// `{"type": "long", "logicalType": "timestamp-millis"}`
+    TimestampMillis,
+    /// `{"type": "long", "logicalType": "timestamp-micros"}`
+    TimestampMicros,
+    /// `{"type": "fixed", "size": 12, "logicalType": "duration"}`
+    Duration(Fixed),
 }
 
 impl From<Enum> for Schema {
@@ -130,3 +176,38 @@ impl From<Fixed> for Schema {
         Schema::Fixed(fixed)
     }
 }
+
+impl Schema {
+    /// Parses a top-level `.avsc` document, resolving any `"type"` strings
+    /// that reference a previously-defined record/enum/fixed in the same
+    /// document. This is a thin wrapper over `serde_json::from_str`: the
+    /// `Deserialize` impl resets its own resolution state on every
+    /// independent top-level parse, so calling `serde_json::from_str::<Schema>`
+    /// directly works the same way.
+    pub fn parse_str(json: &str) -> serde_json::Result<Schema> {
+        serde_json::from_str(json)
+    }
+
+    /// Like [`Schema::parse_str`], additionally running the validation pass
+    /// described by `options` over the resulting schema.
+    pub fn parse_str_with_options(json: &str, options: &ParseOptions) -> serde_json::Result<Schema> {
+        let schema = Self::parse_str(json)?;
+        if options.should_validate() {
+            validate::validate(&schema).map_err(<serde_json::Error as serde::de::Error>::custom)?;
+        }
+        Ok(schema)
+    }
+
+    /// Resolves `writer` against `self` as the reader schema, returning
+    /// every way in which data written with `writer` can't be read with
+    /// `self`. An empty result means they're compatible.
+    pub fn resolve(&self, writer: &Schema) -> Vec<Incompatibility> {
+        resolve::resolve(writer, self)
+    }
+
+    /// Whether data written with `writer` can be read using `self` as the
+    /// reader schema.
+    pub fn can_read(&self, writer: &Schema) -> bool {
+        resolve::can_read(writer, self)
+    }
+}
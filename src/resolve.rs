@@ -0,0 +1,291 @@
+//! Writer/reader schema resolution, following the Avro spec's
+//! [schema resolution](https://avro.apache.org/docs/current/specification/#schema-resolution) rules.
+
+use super::*;
+
+/// A single way in which a writer schema and a reader schema fail to
+/// resolve, with a path into the schema (field/branch names) pointing at
+/// where the mismatch was found.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Incompatibility {
+    pub path: Vec<String>,
+    pub reason: String,
+}
+
+impl Incompatibility {
+    fn new(path: &[String], reason: impl Into<String>) -> Self {
+        Self {
+            path: path.to_vec(),
+            reason: reason.into(),
+        }
+    }
+}
+
+/// Resolves a writer schema against a reader schema, returning every
+/// incompatibility found. An empty report means data written with `writer`
+/// can be read with `reader`.
+pub fn resolve(writer: &Schema, reader: &Schema) -> Vec<Incompatibility> {
+    let mut report = Vec::new();
+    let mut path = Vec::new();
+    resolve_at(writer, reader, &mut path, &mut report);
+    report
+}
+
+/// Whether data written with `writer` can be read using `reader`.
+pub fn can_read(writer: &Schema, reader: &Schema) -> bool {
+    resolve(writer, reader).is_empty()
+}
+
+fn named_fullname(name: &str, namespace: Option<&str>) -> String {
+    de::fullname(name, namespace)
+}
+
+/// Whether the writer's named type is the one the reader means, either
+/// because the fullnames match or because one of the reader's aliases does.
+fn named_match(
+    writer_name: &str,
+    writer_namespace: Option<&str>,
+    reader_name: &str,
+    reader_namespace: Option<&str>,
+    reader_aliases: &[String],
+) -> bool {
+    let writer_fullname = named_fullname(writer_name, writer_namespace);
+    if writer_fullname == named_fullname(reader_name, reader_namespace) {
+        return true;
+    }
+    reader_aliases
+        .iter()
+        .any(|alias| named_fullname(alias, reader_namespace) == writer_fullname)
+}
+
+fn is_logical(schema: &Schema) -> bool {
+    use Schema::*;
+    matches!(
+        schema,
+        Uuid | Date | TimeMillis | TimeMicros | TimestampMillis | TimestampMicros | Decimal { .. } | Duration(_)
+    )
+}
+
+/// The underlying physical type a logical type is carried over, per the
+/// Avro spec (e.g. `date` is carried over `int`). Non-logical schemas are
+/// their own physical type.
+fn physical(schema: &Schema) -> Schema {
+    use Schema::*;
+    match schema {
+        Uuid => String,
+        Date | TimeMillis => Int,
+        TimeMicros | TimestampMillis | TimestampMicros => Long,
+        Decimal { inner, .. } => (**inner).clone(),
+        Duration(fixed) => Fixed(fixed.clone()),
+        other => other.clone(),
+    }
+}
+
+fn resolve_at(writer: &Schema, reader: &Schema, path: &mut Vec<String>, report: &mut Vec<Incompatibility>) {
+    use Schema::*;
+
+    // A writer union resolves if every branch resolves against the reader
+    // (which may itself be a union).
+    if let Union(branches) = writer {
+        for (i, branch) in branches.iter().enumerate() {
+            path.push(format!("union[{}]", i));
+            resolve_at(branch, reader, path, report);
+            path.pop();
+        }
+        return;
+    }
+
+    // A non-union writer resolves against a reader union if it resolves
+    // against at least one of the reader's branches.
+    if let Union(branches) = reader {
+        let matches = branches
+            .iter()
+            .any(|branch| resolve(writer, branch).is_empty());
+        if !matches {
+            report.push(Incompatibility::new(
+                path,
+                "writer schema does not resolve against any reader union branch",
+            ));
+        }
+        return;
+    }
+
+    match (writer, reader) {
+        (Null, Null) | (Boolean, Boolean) | (Bytes, Bytes) | (String, String) => {}
+        (Int, Int) | (Long, Long) | (Float, Float) | (Double, Double) => {}
+
+        // Promotion matrix.
+        (Int, Long) | (Int, Float) | (Int, Double) => {}
+        (Long, Float) | (Long, Double) => {}
+        (Float, Double) => {}
+        (String, Bytes) | (Bytes, String) => {}
+
+        (Array(w), Array(r)) => {
+            path.push("items".into());
+            resolve_at(w, r, path, report);
+            path.pop();
+        }
+        (Map(w), Map(r)) => {
+            path.push("values".into());
+            resolve_at(w, r, path, report);
+            path.pop();
+        }
+
+        (Fixed(w), Fixed(r)) => {
+            if !named_match(&w.name, w.namespace.as_deref(), &r.name, r.namespace.as_deref(), &r.aliases) {
+                report.push(Incompatibility::new(path, format!("fixed name mismatch: writer `{}`, reader `{}`", w.name, r.name)));
+            } else if w.size != r.size {
+                report.push(Incompatibility::new(path, format!("fixed size mismatch: writer {}, reader {}", w.size, r.size)));
+            }
+        }
+
+        (Enum(w), Enum(r)) => {
+            if !named_match(&w.name, w.namespace.as_deref(), &r.name, r.namespace.as_deref(), &r.aliases) {
+                report.push(Incompatibility::new(path, format!("enum name mismatch: writer `{}`, reader `{}`", w.name, r.name)));
+            } else {
+                for symbol in &w.symbols {
+                    if !r.symbols.contains(symbol) && r.default.is_none() {
+                        report.push(Incompatibility::new(
+                            path,
+                            format!("writer symbol `{}` is missing from reader and reader has no default", symbol),
+                        ));
+                    }
+                }
+            }
+        }
+
+        (Record(w), Record(r)) => {
+            if !named_match(&w.name, w.namespace.as_deref(), &r.name, r.namespace.as_deref(), &r.aliases) {
+                report.push(Incompatibility::new(path, format!("record name mismatch: writer `{}`, reader `{}`", w.name, r.name)));
+                return;
+            }
+
+            for reader_field in &r.fields {
+                path.push(reader_field.name.clone());
+                let writer_field = w.fields.iter().find(|f| {
+                    f.name == reader_field.name || reader_field.aliases.contains(&f.name)
+                });
+                match writer_field {
+                    Some(writer_field) => resolve_at(&writer_field.schema, &reader_field.schema, path, report),
+                    None if reader_field.default.is_some() => {}
+                    None => report.push(Incompatibility::new(
+                        path,
+                        format!("field `{}` is missing from the writer and the reader field has no default", reader_field.name),
+                    )),
+                }
+                path.pop();
+            }
+        }
+
+        (Decimal { precision: wp, scale: ws, .. }, Decimal { precision: rp, scale: rs, .. }) => {
+            if wp != rp || ws != rs {
+                report.push(Incompatibility::new(path, "decimal precision/scale mismatch between writer and reader"));
+            }
+        }
+        (Uuid, Uuid) | (Date, Date) | (TimeMillis, TimeMillis) | (TimeMicros, TimeMicros) => {}
+        (TimestampMillis, TimestampMillis) | (TimestampMicros, TimestampMicros) => {}
+        (Duration(w), Duration(r)) if w.size == r.size => {}
+
+        // A writer and reader reference to the same (unresolved) named type
+        // are assumed compatible. This is what keeps a recursive/
+        // self-referential named type (e.g. a linked-list `next` field whose
+        // type is `["null", "Node"]` inside `Node` itself) from being
+        // reported as incompatible purely because `Node` hadn't finished
+        // registering yet when its own field was parsed.
+        (Reference(w), Reference(r)) if w == r => {}
+
+        (Reference(name), _) | (_, Reference(name)) => {
+            report.push(Incompatibility::new(path, format!("unresolved schema reference `{}`", name)));
+        }
+
+        // A logical type resolves against its underlying physical type (and
+        // vice versa), per the Avro spec, e.g. a writer `date` can be read
+        // by a reader `int` and an `int` writer can be read as a `date`.
+        (w, r) if is_logical(w) || is_logical(r) => {
+            resolve_at(&physical(w), &physical(r), path, report)
+        }
+
+        (w, r) => report.push(Incompatibility::new(
+            path,
+            format!("writer type {:?} cannot be read as reader type {:?}", w, r),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn promotion_matrix_is_compatible() {
+        assert!(can_read(&Schema::Int, &Schema::Long));
+        assert!(can_read(&Schema::Int, &Schema::Double));
+        assert!(can_read(&Schema::String, &Schema::Bytes));
+        assert!(can_read(&Schema::Bytes, &Schema::String));
+        assert!(!can_read(&Schema::Long, &Schema::Int));
+    }
+
+    #[test]
+    fn record_field_added_with_default_is_compatible() {
+        let writer = Schema::parse_str(r#"{"type":"record","name":"R","fields":[{"name":"a","type":"int"}]}"#).unwrap();
+        let reader = Schema::parse_str(
+            r#"{"type":"record","name":"R","fields":[{"name":"a","type":"int"},{"name":"b","type":"string","default":"x"}]}"#,
+        )
+        .unwrap();
+        assert!(can_read(&writer, &reader));
+    }
+
+    #[test]
+    fn extra_writer_field_is_ignored() {
+        let writer = Schema::parse_str(
+            r#"{"type":"record","name":"R","fields":[{"name":"a","type":"int"},{"name":"b","type":"string"}]}"#,
+        )
+        .unwrap();
+        let reader = Schema::parse_str(r#"{"type":"record","name":"R","fields":[{"name":"a","type":"int"}]}"#).unwrap();
+        assert!(can_read(&writer, &reader));
+    }
+
+    #[test]
+    fn missing_reader_field_without_default_is_incompatible() {
+        let writer = Schema::parse_str(r#"{"type":"record","name":"R","fields":[{"name":"a","type":"int"}]}"#).unwrap();
+        let reader = Schema::parse_str(
+            r#"{"type":"record","name":"R","fields":[{"name":"a","type":"int"},{"name":"b","type":"string"}]}"#,
+        )
+        .unwrap();
+        assert!(!can_read(&writer, &reader));
+    }
+
+    #[test]
+    fn enum_symbol_missing_without_default_is_incompatible() {
+        let writer = Schema::parse_str(r#"{"type":"enum","name":"E","symbols":["A","B","C"]}"#).unwrap();
+        let reader = Schema::parse_str(r#"{"type":"enum","name":"E","symbols":["A","B"]}"#).unwrap();
+        assert!(!can_read(&writer, &reader));
+
+        let reader_with_default =
+            Schema::parse_str(r#"{"type":"enum","name":"E","symbols":["A","B"],"default":"A"}"#).unwrap();
+        assert!(can_read(&writer, &reader_with_default));
+    }
+
+    #[test]
+    fn recursive_named_type_resolves_against_itself() {
+        let node = Schema::parse_str(
+            r#"{"type":"record","name":"Node","fields":[{"name":"value","type":"int"},{"name":"next","type":["null","Node"]}]}"#,
+        )
+        .unwrap();
+        assert!(can_read(&node, &node));
+    }
+
+    #[test]
+    fn logical_type_resolves_against_its_underlying_physical_type() {
+        let date = Schema::parse_str(r#"{"type":"int","logicalType":"date"}"#).unwrap();
+        assert!(can_read(&date, &Schema::Int));
+        assert!(can_read(&Schema::Int, &date));
+
+        let decimal = Schema::parse_str(r#"{"type":"bytes","logicalType":"decimal","precision":4,"scale":2}"#).unwrap();
+        assert!(can_read(&decimal, &Schema::Bytes));
+
+        let decimal_other_scale =
+            Schema::parse_str(r#"{"type":"bytes","logicalType":"decimal","precision":5,"scale":2}"#).unwrap();
+        assert!(!can_read(&decimal, &decimal_other_scale));
+    }
+}
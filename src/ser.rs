@@ -0,0 +1,248 @@
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+use super::*;
+
+fn primitive_name(schema: &Schema) -> Option<&'static str> {
+    use Schema::*;
+    Some(match schema {
+        Null => "null",
+        Boolean => "boolean",
+        Int => "int",
+        Long => "long",
+        Float => "float",
+        Double => "double",
+        Bytes => "bytes",
+        String => "string",
+        _ => return None,
+    })
+}
+
+fn serialize_named<S: Serializer>(
+    map: &mut S::SerializeMap,
+    name: &str,
+    namespace: &Option<String>,
+    doc: &Option<String>,
+    aliases: &[String],
+) -> Result<(), S::Error> {
+    map.serialize_entry("name", name)?;
+    if let Some(namespace) = namespace {
+        map.serialize_entry("namespace", namespace)?;
+    }
+    if let Some(doc) = doc {
+        map.serialize_entry("doc", doc)?;
+    }
+    if !aliases.is_empty() {
+        map.serialize_entry("aliases", aliases)?;
+    }
+    Ok(())
+}
+
+impl Serialize for Schema {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Some(name) = primitive_name(self) {
+            return serializer.serialize_str(name);
+        }
+
+        match self {
+            Schema::Reference(name) => serializer.serialize_str(name),
+            Schema::Union(variants) => variants.serialize(serializer),
+            Schema::Array(items) => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("type", "array")?;
+                map.serialize_entry("items", items.as_ref())?;
+                map.end()
+            }
+            Schema::Map(values) => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("type", "map")?;
+                map.serialize_entry("values", values.as_ref())?;
+                map.end()
+            }
+            Schema::Record(record) => record.serialize(serializer),
+            Schema::Enum(enum_) => enum_.serialize(serializer),
+            Schema::Fixed(fixed) => fixed.serialize(serializer),
+            Schema::Decimal {
+                precision,
+                scale,
+                inner,
+            } => {
+                let mut map = serializer.serialize_map(None)?;
+                serialize_inner_type::<S>(&mut map, inner)?;
+                map.serialize_entry("logicalType", "decimal")?;
+                map.serialize_entry("precision", precision)?;
+                map.serialize_entry("scale", scale)?;
+                map.end()
+            }
+            Schema::Uuid => serialize_logical(serializer, "string", "uuid"),
+            Schema::Date => serialize_logical(serializer, "int", "date"),
+            Schema::TimeMillis => serialize_logical(serializer, "int", "time-millis"),
+            Schema::TimeMicros => serialize_logical(serializer, "long", "time-micros"),
+            Schema::TimestampMillis => serialize_logical(serializer, "long", "timestamp-millis"),
+            Schema::TimestampMicros => serialize_logical(serializer, "long", "timestamp-micros"),
+            Schema::Duration(fixed) => {
+                let mut map = serializer.serialize_map(None)?;
+                map.serialize_entry("type", "fixed")?;
+                serialize_named::<S>(&mut map, &fixed.name, &fixed.namespace, &fixed.doc, &fixed.aliases)?;
+                map.serialize_entry("size", &fixed.size)?;
+                map.serialize_entry("logicalType", "duration")?;
+                map.end()
+            }
+            _ => unreachable!("primitive schemas are handled above"),
+        }
+    }
+}
+
+fn serialize_logical<S: Serializer>(
+    serializer: S,
+    base: &'static str,
+    logical_type: &'static str,
+) -> Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(Some(2))?;
+    map.serialize_entry("type", base)?;
+    map.serialize_entry("logicalType", logical_type)?;
+    map.end()
+}
+
+/// Writes the `type` (and, for `fixed`, the rest of its named-type fields)
+/// that a `Decimal`'s `bytes`/`fixed` inner schema contributes.
+fn serialize_inner_type<S: Serializer>(
+    map: &mut S::SerializeMap,
+    inner: &Schema,
+) -> Result<(), S::Error> {
+    match inner {
+        Schema::Bytes => map.serialize_entry("type", "bytes"),
+        Schema::Fixed(fixed) => {
+            map.serialize_entry("type", "fixed")?;
+            serialize_named::<S>(map, &fixed.name, &fixed.namespace, &fixed.doc, &fixed.aliases)?;
+            map.serialize_entry("size", &fixed.size)
+        }
+        _ => unreachable!("decimal only wraps bytes or fixed"),
+    }
+}
+
+impl Serialize for Record {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "record")?;
+        serialize_named::<S>(&mut map, &self.name, &self.namespace, &self.doc, &self.aliases)?;
+        map.serialize_entry("fields", &self.fields)?;
+        map.end()
+    }
+}
+
+impl Serialize for Fixed {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "fixed")?;
+        serialize_named::<S>(&mut map, &self.name, &self.namespace, &self.doc, &self.aliases)?;
+        map.serialize_entry("size", &self.size)?;
+        map.end()
+    }
+}
+
+impl Serialize for Enum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", "enum")?;
+        serialize_named::<S>(&mut map, &self.name, &self.namespace, &self.doc, &self.aliases)?;
+        map.serialize_entry("symbols", &self.symbols)?;
+        if let Some(default) = &self.default {
+            map.serialize_entry("default", default)?;
+        }
+        map.end()
+    }
+}
+
+impl Serialize for Order {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(match self {
+            Order::Ascending => "ascending",
+            Order::Descending => "descending",
+            Order::Ignore => "ignore",
+        })
+    }
+}
+
+impl Serialize for Field {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("name", &self.name)?;
+        if let Some(doc) = &self.doc {
+            map.serialize_entry("doc", doc)?;
+        }
+        map.serialize_entry("type", &self.schema)?;
+        if let Some(default) = &self.default {
+            map.serialize_entry("default", default)?;
+        }
+        if let Some(order) = &self.order {
+            map.serialize_entry("order", order)?;
+        }
+        if !self.aliases.is_empty() {
+            map.serialize_entry("aliases", &self.aliases)?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip(json: &str) -> Schema {
+        let schema = Schema::parse_str(json).unwrap();
+        let serialized = serde_json::to_string(&schema).unwrap();
+        let reparsed = Schema::parse_str(&serialized).unwrap();
+        assert_eq!(schema, reparsed, "serialized form: {}", serialized);
+        schema
+    }
+
+    #[test]
+    fn roundtrips_primitives() {
+        for json in ["\"null\"", "\"boolean\"", "\"int\"", "\"long\"", "\"float\"", "\"double\"", "\"bytes\"", "\"string\""] {
+            roundtrip(json);
+        }
+    }
+
+    #[test]
+    fn roundtrips_record_with_nested_named_types() {
+        let schema = roundtrip(
+            r#"{
+                "type": "record",
+                "name": "Person",
+                "namespace": "com.example",
+                "fields": [
+                    {"name": "name", "type": "string"},
+                    {"name": "favorite_color", "type": ["null", {"type": "enum", "name": "Color", "symbols": ["RED", "GREEN", "BLUE"]}], "default": null},
+                    {"name": "tags", "type": {"type": "array", "items": "string"}, "default": []}
+                ]
+            }"#,
+        );
+        assert!(matches!(schema, Schema::Record(_)));
+    }
+
+    #[test]
+    fn roundtrips_logical_types() {
+        roundtrip(r#"{"type": "string", "logicalType": "uuid"}"#);
+        roundtrip(r#"{"type": "int", "logicalType": "date"}"#);
+        roundtrip(r#"{"type": "bytes", "logicalType": "decimal", "precision": 9, "scale": 2}"#);
+        roundtrip(r#"{"type": "fixed", "name": "Money", "size": 12, "logicalType": "duration"}"#);
+    }
+}
@@ -0,0 +1,232 @@
+//! An opt-in validation pass over a parsed [`Schema`], catching malformed
+//! names, enum symbols and default values that the visitors in `de` accept
+//! without checking.
+
+use std::collections::HashSet;
+
+use serde_json::Value;
+
+use super::*;
+
+/// Options controlling how a schema is parsed. Currently only toggles the
+/// validation pass; defaults to validation off, matching the historical,
+/// permissive parsing behavior.
+#[derive(Debug, Clone, Default)]
+pub struct ParseOptions {
+    validate: bool,
+}
+
+impl ParseOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables the name/symbol/default validation pass.
+    pub fn validate(mut self, validate: bool) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    pub(crate) fn should_validate(&self) -> bool {
+        self.validate
+    }
+}
+
+/// The Avro name grammar: `^[A-Za-z_][A-Za-z0-9_]*$`.
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// A namespace is a dotted sequence of valid names.
+fn is_valid_namespace(namespace: &str) -> bool {
+    !namespace.is_empty() && namespace.split('.').all(is_valid_name)
+}
+
+fn validate_named(name: &str, namespace: Option<&str>) -> Result<(), String> {
+    // `name` may itself carry the namespace as a dotted fullname.
+    if !(is_valid_name(name) || (name.contains('.') && is_valid_namespace(name))) {
+        return Err(format!("`{}` is not a valid Avro name", name));
+    }
+    if let Some(namespace) = namespace {
+        if !is_valid_namespace(namespace) {
+            return Err(format!("`{}` is not a valid Avro namespace", namespace));
+        }
+    }
+    Ok(())
+}
+
+/// Walks a schema tree, checking names, enum symbols/defaults and field
+/// defaults against the Avro spec.
+pub(crate) fn validate(schema: &Schema) -> Result<(), String> {
+    match schema {
+        Schema::Record(record) => {
+            validate_named(&record.name, record.namespace.as_deref())?;
+            let mut seen = HashSet::new();
+            for field in &record.fields {
+                if !seen.insert(field.name.as_str()) {
+                    return Err(format!(
+                        "duplicate field name `{}` in record `{}`",
+                        field.name, record.name
+                    ));
+                }
+                validate(&field.schema)?;
+                if let Some(default) = &field.default {
+                    validate_default(&field.schema, default)?;
+                }
+            }
+            Ok(())
+        }
+        Schema::Enum(enum_) => {
+            validate_named(&enum_.name, enum_.namespace.as_deref())?;
+            let mut seen = HashSet::new();
+            for symbol in &enum_.symbols {
+                if !is_valid_name(symbol) {
+                    return Err(format!("`{}` is not a valid enum symbol", symbol));
+                }
+                if !seen.insert(symbol.as_str()) {
+                    return Err(format!(
+                        "duplicate enum symbol `{}` in enum `{}`",
+                        symbol, enum_.name
+                    ));
+                }
+            }
+            if let Some(default) = &enum_.default {
+                if !enum_.symbols.iter().any(|s| s == default) {
+                    return Err(format!(
+                        "enum `{}` default `{}` is not one of its symbols",
+                        enum_.name, default
+                    ));
+                }
+            }
+            Ok(())
+        }
+        Schema::Fixed(fixed) => validate_named(&fixed.name, fixed.namespace.as_deref()),
+        Schema::Duration(fixed) => validate_named(&fixed.name, fixed.namespace.as_deref()),
+        Schema::Array(items) => validate(items),
+        Schema::Map(values) => validate(values),
+        Schema::Union(variants) => variants.iter().try_for_each(validate),
+        Schema::Decimal { inner, .. } => validate(inner),
+        Schema::Null
+        | Schema::Boolean
+        | Schema::Int
+        | Schema::Long
+        | Schema::Float
+        | Schema::Double
+        | Schema::Bytes
+        | Schema::String
+        | Schema::Reference(_)
+        | Schema::Uuid
+        | Schema::Date
+        | Schema::TimeMillis
+        | Schema::TimeMicros
+        | Schema::TimestampMillis
+        | Schema::TimestampMicros => Ok(()),
+    }
+}
+
+/// Checks that a field's `default` JSON value is type-compatible with its
+/// schema. For a union, the default must match the *first* branch, per spec.
+fn validate_default(schema: &Schema, default: &Value) -> Result<(), String> {
+    use Schema::*;
+    let compatible = match schema {
+        Null => default.is_null(),
+        Boolean => default.is_boolean(),
+        Int | Long | Date | TimeMillis | TimeMicros | TimestampMillis | TimestampMicros => {
+            default.is_i64() || default.is_u64()
+        }
+        Float | Double => default.is_number(),
+        Bytes | String | Uuid | Decimal { .. } => default.is_string(),
+        Array(_) => default.is_array(),
+        Map(_) | Record(_) => default.is_object(),
+        Enum(enum_) => default
+            .as_str()
+            .is_some_and(|s| enum_.symbols.iter().any(|symbol| symbol == s)),
+        Fixed(_) | Duration(_) => default.is_string(),
+        Union(variants) => {
+            return match variants.first() {
+                Some(first) => validate_default(first, default),
+                None => Err("union has no branches to match a default against".to_string()),
+            }
+        }
+        // Can't be validated without resolving the reference first.
+        Reference(_) => true,
+    };
+    if compatible {
+        Ok(())
+    } else {
+        Err(format!(
+            "default value `{}` is not compatible with schema {:?}",
+            default, schema
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_with_validation(json: &str) -> serde_json::Result<Schema> {
+        Schema::parse_str_with_options(json, &ParseOptions::new().validate(true))
+    }
+
+    #[test]
+    fn accepts_malformed_name_without_validation() {
+        assert!(Schema::parse_str(r#"{"type":"record","name":"1Bad","fields":[]}"#).is_ok());
+    }
+
+    #[test]
+    fn rejects_invalid_record_name() {
+        let err = parse_with_validation(r#"{"type":"record","name":"1Bad","fields":[]}"#).unwrap_err();
+        assert!(err.to_string().contains("not a valid Avro name"), "{err}");
+    }
+
+    #[test]
+    fn rejects_invalid_namespace() {
+        let err =
+            parse_with_validation(r#"{"type":"record","name":"R","namespace":"com.1bad","fields":[]}"#).unwrap_err();
+        assert!(err.to_string().contains("not a valid Avro namespace"), "{err}");
+    }
+
+    #[test]
+    fn rejects_duplicate_field_names() {
+        let err = parse_with_validation(
+            r#"{"type":"record","name":"R","fields":[{"name":"a","type":"int"},{"name":"a","type":"string"}]}"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("duplicate field name"), "{err}");
+    }
+
+    #[test]
+    fn rejects_duplicate_enum_symbols() {
+        let err = parse_with_validation(r#"{"type":"enum","name":"E","symbols":["A","A"]}"#).unwrap_err();
+        assert!(err.to_string().contains("duplicate enum symbol"), "{err}");
+    }
+
+    #[test]
+    fn rejects_enum_default_not_in_symbols() {
+        let err = parse_with_validation(r#"{"type":"enum","name":"E","symbols":["A","B"],"default":"C"}"#).unwrap_err();
+        assert!(err.to_string().contains("is not one of its symbols"), "{err}");
+    }
+
+    #[test]
+    fn rejects_field_default_incompatible_with_type() {
+        let err = parse_with_validation(
+            r#"{"type":"record","name":"R","fields":[{"name":"a","type":"int","default":"not-a-number"}]}"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("is not compatible with schema"), "{err}");
+    }
+
+    #[test]
+    fn accepts_default_matching_first_union_branch() {
+        assert!(parse_with_validation(
+            r#"{"type":"record","name":"R","fields":[{"name":"a","type":["null","string"],"default":null}]}"#
+        )
+        .is_ok());
+    }
+}